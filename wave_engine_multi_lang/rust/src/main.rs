@@ -1,25 +1,322 @@
-/**
+/*
  * Rust Wave Engine Implementation
  * Ultra-fast wave-based cognition engine
  * Memory-safe systems programming
  */
 
 use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::sync::Arc;
 use std::time::Instant;
 use std::f64::consts::PI;
 
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Bumped whenever the shape of `WaveState` changes, so old snapshots can be
+/// detected instead of silently misread.
+const WAVE_STATE_SCHEMA_VERSION: u32 = 1;
+
+/// The derived frequency/amplitude/phase a `HashMode` assigns to one symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WaveParams {
+    pub frequency: f64,
+    pub amplitude: f64,
+    pub phase: f64,
+}
+
+/// On-disk representation of a `WaveEngine`'s active waves. Captures the
+/// derived per-symbol parameters and the `HashMode` that produced them, so a
+/// reloaded engine recomputes byte-identical waves rather than silently
+/// switching hash modes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaveState {
+    pub schema_version: u32,
+    pub active_waves: HashMap<String, f64>,
+    pub wave_params: HashMap<String, WaveParams>,
+    pub hash_mode: HashMode,
+}
+
+/// Codec used to persist a `WaveState` snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SerializationFormat {
+    /// Human-readable, useful for debugging and diffing snapshots.
+    Json,
+    /// Compact self-describing binary format.
+    Cbor,
+    /// Fastest and smallest; not self-describing, so format must match on load.
+    Bincode,
+}
+
+/// Selects how a symbol string is turned into wave parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HashMode {
+    /// Legacy `hash * 31 + byte` accumulator. Only this mode is guaranteed to
+    /// match the Python reference implementation's output, byte for byte.
+    PythonCompat,
+    /// BLAKE3 keyed hash. Frequency, amplitude, and phase are derived from
+    /// disjoint byte ranges of the digest, so the three are statistically
+    /// independent, unlike `PythonCompat`'s correlated multiplier hash.
+    Blake3Keyed([u8; 32]),
+}
+
+/// Wraps `Instant` to produce a monotonically increasing simulation time in seconds.
+pub struct MonotonicClock {
+    start: Instant,
+}
+
+impl MonotonicClock {
+    pub fn new() -> Self {
+        MonotonicClock {
+            start: Instant::now(),
+        }
+    }
+
+    fn now(&self) -> f64 {
+        self.start.elapsed().as_secs_f64()
+    }
+}
+
+impl Default for MonotonicClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Advances by the `dt` passed to each `step`, independent of wall-clock time.
+/// The `dt` given at construction is stored and returned by `dt()`; `step`
+/// does not use it on its own.
+/// Deterministic and reproducible across machines, so tests should prefer this clock.
+pub struct VirtualClock {
+    time: f64,
+    dt: f64,
+}
+
+impl VirtualClock {
+    pub fn new(dt: f64) -> Self {
+        VirtualClock { time: 0.0, dt }
+    }
+
+    fn now(&self) -> f64 {
+        self.time
+    }
+
+    /// The fixed increment configured at construction. Callers that want a
+    /// steady tick cadence should pass this back into repeated `step` calls.
+    pub fn dt(&self) -> f64 {
+        self.dt
+    }
+
+    fn advance(&mut self, dt: f64) -> f64 {
+        self.time += dt;
+        self.time
+    }
+}
+
+/// The engine's source of simulation time, either real or virtual.
+pub enum SimClock {
+    Monotonic(MonotonicClock),
+    Virtual(VirtualClock),
+}
+
+impl SimClock {
+    /// Current simulation time in seconds. Never decreases between calls.
+    fn now(&self) -> f64 {
+        match self {
+            SimClock::Monotonic(c) => c.now(),
+            SimClock::Virtual(c) => c.now(),
+        }
+    }
+
+    /// Advances the clock by `dt` and returns the new time. On `Monotonic`,
+    /// `dt` is ignored since real time advances on its own.
+    fn step(&mut self, dt: f64) -> f64 {
+        match self {
+            SimClock::Monotonic(c) => c.now(),
+            SimClock::Virtual(c) => c.advance(dt),
+        }
+    }
+}
+
+/// Tuning knobs for `WaveEngine::process_parallel`.
+#[derive(Debug, Clone, Copy)]
+pub struct ParallelConfig {
+    /// Symbol counts at or below this fall back to the serial path, avoiding
+    /// the overhead of spinning up threads for small batches.
+    pub threshold: usize,
+    /// Worker threads to use. `0` means use rayon's global thread pool.
+    pub num_threads: usize,
+}
+
+impl Default for ParallelConfig {
+    fn default() -> Self {
+        ParallelConfig {
+            threshold: 1000,
+            num_threads: 0,
+        }
+    }
+}
+
+/// Default width, in Hz, of the frequency buckets `WaveEngine::interference`
+/// groups symbols into. Matches the step size of `HashMode::PythonCompat`'s
+/// frequency derivation, so bit-identical legacy frequencies always collide.
+const DEFAULT_INTERFERENCE_BIN_WIDTH: f64 = 0.01;
+
+/// The resultant of summing every component symbol's phasor within one
+/// frequency bucket, i.e. classic wave interference/superposition.
+#[derive(Debug, Clone)]
+pub struct InterferenceBin {
+    /// Center frequency of this bucket, in Hz.
+    pub bin_frequency: f64,
+    /// Amplitude of the summed phasor. Equals the sum of component
+    /// amplitudes when all phases align (fully constructive), and can drop
+    /// toward zero when phases oppose (fully destructive).
+    pub resultant_amplitude: f64,
+    /// Phase of the summed phasor, in radians.
+    pub resultant_phase: f64,
+    /// Degree of destructive cancellation in this bin: 0.0 when component
+    /// waves combine fully constructively, approaching 1.0 as they cancel.
+    pub contradiction_score: f64,
+    /// Symbols that fell into this bucket.
+    pub symbols: Vec<String>,
+}
+
 pub struct WaveEngine {
     active_waves: HashMap<String, f64>,
+    wave_param_cache: HashMap<String, WaveParams>,
+    hash_mode: HashMode,
+    clock: SimClock,
+    parallel_config: ParallelConfig,
+    /// Built once by `set_parallel_config` when `num_threads > 0`, reused by
+    /// every `process_parallel` call instead of spinning up a fresh pool.
+    thread_pool: Option<Arc<rayon::ThreadPool>>,
+    interference_bin_width: f64,
 }
 
 impl WaveEngine {
-    /// Constructor
+    /// Constructor. Defaults to `HashMode::PythonCompat` and a real-time `MonotonicClock`.
     pub fn new() -> Self {
         WaveEngine {
             active_waves: HashMap::new(),
+            wave_param_cache: HashMap::new(),
+            hash_mode: HashMode::PythonCompat,
+            clock: SimClock::Monotonic(MonotonicClock::new()),
+            parallel_config: ParallelConfig::default(),
+            thread_pool: None,
+            interference_bin_width: DEFAULT_INTERFERENCE_BIN_WIDTH,
+        }
+    }
+
+    /// Constructor with an explicit hash mode.
+    pub fn new_with_hash_mode(hash_mode: HashMode) -> Self {
+        WaveEngine {
+            active_waves: HashMap::new(),
+            wave_param_cache: HashMap::new(),
+            hash_mode,
+            clock: SimClock::Monotonic(MonotonicClock::new()),
+            parallel_config: ParallelConfig::default(),
+            thread_pool: None,
+            interference_bin_width: DEFAULT_INTERFERENCE_BIN_WIDTH,
         }
     }
 
+    /// Constructor with an explicit simulation clock, e.g. a `VirtualClock` for deterministic tests.
+    pub fn new_with_clock(clock: SimClock) -> Self {
+        WaveEngine {
+            active_waves: HashMap::new(),
+            wave_param_cache: HashMap::new(),
+            hash_mode: HashMode::PythonCompat,
+            clock,
+            parallel_config: ParallelConfig::default(),
+            thread_pool: None,
+            interference_bin_width: DEFAULT_INTERFERENCE_BIN_WIDTH,
+        }
+    }
+
+    /// Tune the thresholds `process_parallel` uses to decide between the serial
+    /// and rayon paths. When `num_threads > 0` this builds the dedicated thread
+    /// pool once, up front, so `process_parallel` never pays pool setup cost.
+    pub fn set_parallel_config(
+        &mut self,
+        parallel_config: ParallelConfig,
+    ) -> Result<(), rayon::ThreadPoolBuildError> {
+        self.thread_pool = if parallel_config.num_threads > 0 {
+            Some(Arc::new(
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(parallel_config.num_threads)
+                    .build()?,
+            ))
+        } else {
+            None
+        };
+        self.parallel_config = parallel_config;
+        Ok(())
+    }
+
+    /// Set the frequency bucket width `interference` uses to group symbols.
+    pub fn set_interference_bin_width(&mut self, bin_width: f64) {
+        self.interference_bin_width = bin_width;
+    }
+
+    /// Snapshot the engine's active waves to `path` using the given codec.
+    pub fn save(&self, path: &str, format: SerializationFormat) -> io::Result<()> {
+        let state = WaveState {
+            schema_version: WAVE_STATE_SCHEMA_VERSION,
+            active_waves: self.active_waves.clone(),
+            wave_params: self.wave_param_cache.clone(),
+            hash_mode: self.hash_mode,
+        };
+        let mut file = std::fs::File::create(path)?;
+        match format {
+            SerializationFormat::Json => {
+                serde_json::to_writer_pretty(&mut file, &state)
+                    .map_err(io::Error::other)?;
+            }
+            SerializationFormat::Cbor => {
+                ciborium::into_writer(&state, &mut file)
+                    .map_err(io::Error::other)?;
+            }
+            SerializationFormat::Bincode => {
+                let bytes = bincode::serialize(&state)
+                    .map_err(io::Error::other)?;
+                file.write_all(&bytes)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Restore an engine from a snapshot written by `save` with the same format.
+    pub fn load(path: &str, format: SerializationFormat) -> io::Result<Self> {
+        let mut file = std::fs::File::open(path)?;
+        let state: WaveState = match format {
+            SerializationFormat::Json => serde_json::from_reader(&file)
+                .map_err(io::Error::other)?,
+            SerializationFormat::Cbor => ciborium::from_reader(&file)
+                .map_err(io::Error::other)?,
+            SerializationFormat::Bincode => {
+                let mut bytes = Vec::new();
+                file.read_to_end(&mut bytes)?;
+                bincode::deserialize(&bytes)
+                    .map_err(io::Error::other)?
+            }
+        };
+        if state.schema_version != WAVE_STATE_SCHEMA_VERSION {
+            return Err(io::Error::other(format!(
+                "unsupported WaveState schema version {} (expected {})",
+                state.schema_version, WAVE_STATE_SCHEMA_VERSION
+            )));
+        }
+        Ok(WaveEngine {
+            active_waves: state.active_waves,
+            wave_param_cache: state.wave_params,
+            hash_mode: state.hash_mode,
+            clock: SimClock::Monotonic(MonotonicClock::new()),
+            parallel_config: ParallelConfig::default(),
+            thread_pool: None,
+            interference_bin_width: DEFAULT_INTERFERENCE_BIN_WIDTH,
+        })
+    }
+
     /// Hash function compatible with Python's hash() % operation
     fn hash_code(&self, s: &str) -> u32 {
         let mut hash: u32 = 0;
@@ -29,47 +326,169 @@ impl WaveEngine {
         hash
     }
 
-    /// Process symbols through wave interference
-    /// 
+    /// BLAKE3-keyed hash of `s`, returned as a 32-byte digest for splitting
+    /// into disjoint ranges per wave parameter.
+    fn hash_digest_blake3(&self, s: &str, key: &[u8; 32]) -> [u8; 32] {
+        blake3::Hasher::new_keyed(key)
+            .update(s.as_bytes())
+            .finalize()
+            .into()
+    }
+
+    /// Derive (frequency, amplitude, phase) for `symbol` under the engine's current `HashMode`.
+    fn wave_params(&self, symbol: &str) -> (f64, f64, f64) {
+        match self.hash_mode {
+            HashMode::PythonCompat => {
+                let frequency = 1.0 + (self.hash_code(symbol) % 100) as f64 / 100.0;
+                let amplitude = 0.5 + (symbol.len() % 10) as f64 / 20.0;
+                let phase = (self.hash_code(symbol) % 628) as f64 / 100.0;
+                (frequency, amplitude, phase)
+            }
+            HashMode::Blake3Keyed(key) => {
+                let digest = self.hash_digest_blake3(symbol, &key);
+                let freq_bytes: [u8; 8] = digest[0..8].try_into().unwrap();
+                let amp_bytes: [u8; 8] = digest[8..16].try_into().unwrap();
+                let phase_bytes: [u8; 8] = digest[16..24].try_into().unwrap();
+                let frequency = 1.0 + (u64::from_le_bytes(freq_bytes) % 100) as f64 / 100.0;
+                let amplitude = 0.5 + (u64::from_le_bytes(amp_bytes) % 10) as f64 / 20.0;
+                let phase = (u64::from_le_bytes(phase_bytes) % 628) as f64 / 100.0;
+                (frequency, amplitude, phase)
+            }
+        }
+    }
+
+    /// Evaluate a single symbol's wave at `time`. Pure function of `symbol` and
+    /// `time`, so it's safe to call concurrently across symbols.
+    fn wave_value(&self, symbol: &str, time: f64) -> f64 {
+        let (frequency, amplitude, phase) = self.wave_params(symbol);
+        amplitude * (2.0 * PI * frequency * time + phase).sin()
+    }
+
+    /// Evaluate each symbol's own wave at an explicit simulation `time`.
+    /// Waves are not combined here; see `interference` for superposition.
+    ///
     /// # Arguments
     /// * `symbols` - Vector of symbols to process
-    /// 
+    /// * `time` - Simulation time, in seconds, at which to evaluate each wave
+    ///
     /// # Returns
     /// * HashMap mapping symbols to wave values
-    pub fn process(&mut self, symbols: &[String]) -> HashMap<String, f64> {
-        let start_time = Instant::now();
+    pub fn process(&mut self, symbols: &[String], time: f64) -> HashMap<String, f64> {
         let mut activation_field = HashMap::new();
 
         for symbol in symbols {
-            // Create wave with symbol-based properties (exact same algorithm as Python)
-            let frequency = 1.0 + (self.hash_code(symbol) % 100) as f64 / 100.0;
-            let amplitude = 0.5 + (symbol.len() % 10) as f64 / 20.0;
-            let phase = (self.hash_code(symbol) % 628) as f64 / 100.0;
-
-            // Calculate activation
-            let current_time = Instant::now();
-            let time_diff = current_time.duration_since(start_time).as_secs_f64();
-            let wave_value = amplitude * (2.0 * PI * frequency * time_diff + phase).sin();
+            let (frequency, amplitude, phase) = self.wave_params(symbol);
+            let wave_value = amplitude * (2.0 * PI * frequency * time + phase).sin();
             activation_field.insert(symbol.clone(), wave_value);
+            self.active_waves.insert(symbol.clone(), wave_value);
+            self.wave_param_cache.insert(
+                symbol.clone(),
+                WaveParams {
+                    frequency,
+                    amplitude,
+                    phase,
+                },
+            );
         }
 
         activation_field
     }
 
+    /// Process symbols using the engine's own clock for the current simulation time.
+    pub fn process_now(&mut self, symbols: &[String]) -> HashMap<String, f64> {
+        let time = self.clock.now();
+        self.process(symbols, time)
+    }
+
+    /// Advances the engine's clock by `dt` and processes `symbols` at the new time.
+    pub fn step(&mut self, symbols: &[String], dt: f64) -> HashMap<String, f64> {
+        let time = self.clock.step(dt);
+        self.process(symbols, time)
+    }
+
+    /// Like `process`, but computes each symbol's activation independently
+    /// across threads via rayon. At or below `parallel_config.threshold` symbols it
+    /// falls back to the serial path to avoid paying for thread spawn on
+    /// small batches. Does not update `active_waves`, since callers that want
+    /// a throwaway batch activation shouldn't pay for a mutable borrow.
+    /// For the same `time`, results are identical to `process`.
+    pub fn process_parallel(&self, symbols: &[String], time: f64) -> HashMap<String, f64> {
+        if symbols.len() <= self.parallel_config.threshold {
+            return symbols
+                .iter()
+                .map(|symbol| (symbol.clone(), self.wave_value(symbol, time)))
+                .collect();
+        }
+
+        let compute = || -> HashMap<String, f64> {
+            symbols
+                .par_iter()
+                .map(|symbol| (symbol.clone(), self.wave_value(symbol, time)))
+                .collect()
+        };
+
+        match &self.thread_pool {
+            Some(pool) => pool.install(compute),
+            None => compute(),
+        }
+    }
+
     /// Get current activation for a symbol
-    /// 
+    ///
     /// # Arguments
     /// * `symbol` - Symbol to get activation for
     /// * `time` - Current time
-    /// 
+    ///
     /// # Returns
     /// * Activation value
     pub fn get_activation(&self, symbol: &str, time: f64) -> f64 {
-        let frequency = 1.0 + (self.hash_code(symbol) % 100) as f64 / 100.0;
-        let amplitude = 0.5 + (symbol.len() % 10) as f64 / 20.0;
-        let phase = (self.hash_code(symbol) % 628) as f64 / 100.0;
+        self.wave_value(symbol, time)
+    }
 
-        amplitude * (2.0 * PI * frequency * time + phase).sin()
+    /// Sum each symbol's wave phasor `amplitude * e^{i(2*pi*frequency*time + phase)}`
+    /// into buckets of near-equal frequency, so near-equal-frequency symbols
+    /// constructively or destructively interfere instead of sitting isolated.
+    /// Symbols are bucketed by `round(frequency / interference_bin_width)`.
+    pub fn interference(&self, symbols: &[String], time: f64) -> HashMap<i64, InterferenceBin> {
+        let mut bins: HashMap<i64, (f64, f64, f64, Vec<String>)> = HashMap::new();
+
+        for symbol in symbols {
+            let (frequency, amplitude, phase) = self.wave_params(symbol);
+            let bin_key = (frequency / self.interference_bin_width).round() as i64;
+            let theta = 2.0 * PI * frequency * time + phase;
+
+            let (re, im, amplitude_sum, bin_symbols) =
+                bins.entry(bin_key).or_insert((0.0, 0.0, 0.0, Vec::new()));
+            *re += amplitude * theta.cos();
+            *im += amplitude * theta.sin();
+            *amplitude_sum += amplitude;
+            bin_symbols.push(symbol.clone());
+        }
+
+        bins.into_iter()
+            .map(|(bin_key, (re, im, amplitude_sum, symbols))| {
+                let resultant_amplitude = (re * re + im * im).sqrt();
+                let contradiction_score = if amplitude_sum > 0.0 {
+                    1.0 - (resultant_amplitude / amplitude_sum)
+                } else {
+                    0.0
+                };
+                let bin = InterferenceBin {
+                    bin_frequency: bin_key as f64 * self.interference_bin_width,
+                    resultant_amplitude,
+                    resultant_phase: im.atan2(re),
+                    contradiction_score,
+                    symbols,
+                };
+                (bin_key, bin)
+            })
+            .collect()
+    }
+}
+
+impl Default for WaveEngine {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -89,7 +508,7 @@ pub fn replication_test() -> HashMap<String, f64> {
         "mind".to_string(),
         "brain".to_string(),
     ];
-    let result = engine.process(&test_symbols);
+    let result = engine.process_now(&test_symbols);
 
     println!("Input: {:?}", test_symbols);
     println!("Output: {:?}", result);
@@ -99,7 +518,7 @@ pub fn replication_test() -> HashMap<String, f64> {
     let iterations = 100;
     let start = Instant::now();
     for _ in 0..iterations {
-        engine.process(&[
+        engine.process_now(&[
             "test".to_string(),
             "speed".to_string(),
             "benchmark".to_string(),
@@ -111,13 +530,25 @@ pub fn replication_test() -> HashMap<String, f64> {
     println!("Average processing time: {:.6}s", avg_time);
 
     // Test case 3: Contradiction handling
-    let contradiction_result = engine.process(&[
+    let contradiction_symbols = vec![
         "birds".to_string(),
         "fly".to_string(),
         "penguins".to_string(),
         "cannot".to_string(),
-    ]);
+    ];
+    let contradiction_time = 0.5;
+    let contradiction_result = engine.process(&contradiction_symbols, contradiction_time);
+    let interference_bins = engine.interference(&contradiction_symbols, contradiction_time);
+    let max_contradiction_score = interference_bins
+        .values()
+        .map(|bin| bin.contradiction_score)
+        .fold(0.0_f64, f64::max);
     println!("Contradiction test: {:?}", contradiction_result);
+    println!(
+        "Interference bins: {} (max contradiction score: {:.4})",
+        interference_bins.len(),
+        max_contradiction_score
+    );
 
     // Validation check
     if avg_time < 0.01 {
@@ -138,11 +569,107 @@ pub fn replication_test() -> HashMap<String, f64> {
     let mut validation_result = HashMap::new();
     validation_result.insert("symbols_processed".to_string(), result.len() as f64);
     validation_result.insert("avg_processing_time".to_string(), avg_time);
-    validation_result.insert("contradiction_handled".to_string(), 1.0);
+    validation_result.insert("max_contradiction_score".to_string(), max_contradiction_score);
 
     validation_result
 }
 
 fn main() {
     replication_test();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn virtual_clock_produces_deterministic_activation_series() {
+        let mut engine_a = WaveEngine::new_with_clock(SimClock::Virtual(VirtualClock::new(0.1)));
+        let mut engine_b = WaveEngine::new_with_clock(SimClock::Virtual(VirtualClock::new(0.1)));
+        let symbols = vec!["alpha".to_string(), "beta".to_string()];
+
+        let series_a: Vec<_> = (0..5).map(|_| engine_a.step(&symbols, 0.1)).collect();
+        let series_b: Vec<_> = (0..5).map(|_| engine_b.step(&symbols, 0.1)).collect();
+
+        assert_eq!(series_a, series_b);
+    }
+
+    #[test]
+    fn process_parallel_matches_process_below_threshold() {
+        let mut engine = WaveEngine::new();
+        let symbols: Vec<String> = (0..10).map(|i| format!("symbol-{i}")).collect();
+        let time = 1.2345;
+
+        let serial = engine.process(&symbols, time);
+        let parallel = engine.process_parallel(&symbols, time);
+
+        assert_eq!(serial, parallel);
+    }
+
+    #[test]
+    fn process_parallel_matches_process_above_threshold() {
+        let mut engine = WaveEngine::new();
+        engine
+            .set_parallel_config(ParallelConfig {
+                threshold: 10,
+                num_threads: 2,
+            })
+            .unwrap();
+        let symbols: Vec<String> = (0..50).map(|i| format!("symbol-{i}")).collect();
+        let time = 2.5;
+
+        let serial = engine.process(&symbols, time);
+        let parallel = engine.process_parallel(&symbols, time);
+
+        assert_eq!(serial, parallel);
+    }
+
+    #[test]
+    fn json_and_bincode_round_trips_restore_an_identical_engine() {
+        let mut engine = WaveEngine::new_with_hash_mode(HashMode::Blake3Keyed([7u8; 32]));
+        let symbols = vec!["contradiction".to_string(), "cannot".to_string()];
+        engine.process(&symbols, 0.75);
+
+        let json_path = std::env::temp_dir().join("wave_engine_test_round_trip.json");
+        let bincode_path = std::env::temp_dir().join("wave_engine_test_round_trip.bin");
+        engine
+            .save(json_path.to_str().unwrap(), SerializationFormat::Json)
+            .unwrap();
+        engine
+            .save(bincode_path.to_str().unwrap(), SerializationFormat::Bincode)
+            .unwrap();
+
+        let from_json =
+            WaveEngine::load(json_path.to_str().unwrap(), SerializationFormat::Json).unwrap();
+        let from_bincode =
+            WaveEngine::load(bincode_path.to_str().unwrap(), SerializationFormat::Bincode)
+                .unwrap();
+        std::fs::remove_file(&json_path).ok();
+        std::fs::remove_file(&bincode_path).ok();
+
+        for symbol in &symbols {
+            let original = engine.get_activation(symbol, 1.0);
+            assert_eq!(original, from_json.get_activation(symbol, 1.0));
+            assert_eq!(original, from_bincode.get_activation(symbol, 1.0));
+        }
+    }
+
+    #[test]
+    fn load_rejects_a_mismatched_schema_version() {
+        let engine = WaveEngine::new();
+        let path = std::env::temp_dir().join("wave_engine_test_bad_schema.json");
+        engine
+            .save(path.to_str().unwrap(), SerializationFormat::Json)
+            .unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let mut value: serde_json::Value = serde_json::from_str(&contents).unwrap();
+        value["schema_version"] = serde_json::json!(WAVE_STATE_SCHEMA_VERSION + 1);
+        std::fs::write(&path, value.to_string()).unwrap();
+
+        let result = WaveEngine::load(path.to_str().unwrap(), SerializationFormat::Json);
+        std::fs::remove_file(&path).ok();
+
+        assert!(result.is_err());
+    }
 } 
\ No newline at end of file